@@ -0,0 +1,187 @@
+//! Detects when an already-applied migration's SQL has been edited after the fact.
+//!
+//! Each [`Migration`] is fingerprinted by hashing its version, description, and SQL body. The
+//! fingerprint is recorded in `__migration_checksums` the first time a migration is seen; on
+//! every later startup the recorded value is compared against the current one, so an edit to an
+//! already-shipped migration surfaces as a loud error instead of a silently diverging schema.
+
+use rusqlite::{params, Connection};
+use sha2::{Digest, Sha256};
+use tauri_plugin_sql::Migration;
+
+const CHECKSUM_TABLE: &str = "__migration_checksums";
+
+/// A recorded checksum no longer matches the migration it was computed for, meaning the
+/// migration's description or SQL changed after it had already been applied somewhere.
+#[derive(Debug)]
+pub struct ChecksumMismatch {
+    pub version: i64,
+    pub description: &'static str,
+    pub recorded: String,
+    pub current: String,
+}
+
+impl std::fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "migration {} ({}) has changed since it was applied: recorded checksum {}, current checksum {}",
+            self.version, self.description, self.recorded, self.current
+        )
+    }
+}
+
+impl std::error::Error for ChecksumMismatch {}
+
+/// Everything that can go wrong while verifying migration checksums: either a genuine mismatch,
+/// or an underlying database error (locked db, disk I/O, ...) that should surface to the caller
+/// rather than panic the app at startup.
+#[derive(Debug)]
+pub enum ChecksumError {
+    Mismatch(ChecksumMismatch),
+    Db(rusqlite::Error),
+}
+
+impl std::fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChecksumError::Mismatch(mismatch) => mismatch.fmt(f),
+            ChecksumError::Db(err) => write!(f, "failed to verify migration checksums: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ChecksumError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChecksumError::Mismatch(mismatch) => Some(mismatch),
+            ChecksumError::Db(err) => Some(err),
+        }
+    }
+}
+
+impl From<ChecksumMismatch> for ChecksumError {
+    fn from(mismatch: ChecksumMismatch) -> Self {
+        ChecksumError::Mismatch(mismatch)
+    }
+}
+
+impl From<rusqlite::Error> for ChecksumError {
+    fn from(err: rusqlite::Error) -> Self {
+        ChecksumError::Db(err)
+    }
+}
+
+fn checksum(migration: &Migration) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(migration.version.to_le_bytes());
+    hasher.update(migration.description.as_bytes());
+    hasher.update(migration.sql.as_bytes());
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn ensure_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {CHECKSUM_TABLE} (
+                version INTEGER PRIMARY KEY,
+                checksum TEXT NOT NULL
+            );"
+        ),
+        [],
+    )?;
+
+    Ok(())
+}
+
+/// Verifies every already-applied migration's recorded checksum still matches its current SQL,
+/// then records checksums for any migration seen for the first time. Returns the first mismatch
+/// found, if any, or any underlying database error encountered along the way.
+pub fn verify_checksums(conn: &Connection, migrations: &[Migration]) -> Result<(), ChecksumError> {
+    ensure_table(conn)?;
+
+    for migration in migrations {
+        let current = checksum(migration);
+
+        let recorded: Option<String> = match conn.query_row(
+            &format!("SELECT checksum FROM {CHECKSUM_TABLE} WHERE version = ?1"),
+            params![migration.version],
+            |row| row.get(0),
+        ) {
+            Ok(checksum) => Some(checksum),
+            Err(rusqlite::Error::QueryReturnedNoRows) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        match recorded {
+            Some(recorded) if recorded != current => {
+                return Err(ChecksumMismatch {
+                    version: migration.version,
+                    description: migration.description,
+                    recorded,
+                    current,
+                }
+                .into());
+            }
+            Some(_) => {}
+            None => {
+                conn.execute(
+                    &format!("INSERT INTO {CHECKSUM_TABLE} (version, checksum) VALUES (?1, ?2);"),
+                    params![migration.version, current],
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tauri_plugin_sql::MigrationKind;
+
+    fn migration(sql: &'static str) -> Migration {
+        Migration {
+            version: 1,
+            description: "create widgets",
+            sql,
+            kind: MigrationKind::Up,
+        }
+    }
+
+    #[test]
+    fn accepts_an_unchanged_migration() {
+        let conn = Connection::open_in_memory().unwrap();
+        let original = migration("CREATE TABLE widgets (id INTEGER);");
+
+        verify_checksums(&conn, &[original]).expect("first run should record the checksum");
+
+        let unchanged = migration("CREATE TABLE widgets (id INTEGER);");
+        verify_checksums(&conn, &[unchanged]).expect("identical sql should not be rejected");
+    }
+
+    #[test]
+    fn detects_an_edited_migration() {
+        let conn = Connection::open_in_memory().unwrap();
+        let original = migration("CREATE TABLE widgets (id INTEGER);");
+
+        verify_checksums(&conn, &[original]).expect("first run should record the checksum");
+
+        let edited = migration("CREATE TABLE widgets (id INTEGER, name TEXT);");
+        let err = verify_checksums(&conn, &[edited]).expect_err("edited sql should be rejected");
+
+        let ChecksumError::Mismatch(mismatch) = err else {
+            panic!("expected a checksum mismatch, got: {err}");
+        };
+
+        assert_eq!(mismatch.version, 1);
+        assert_eq!(mismatch.description, "create widgets");
+        assert_ne!(mismatch.recorded, mismatch.current);
+    }
+}
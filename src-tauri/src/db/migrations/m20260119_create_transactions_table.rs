@@ -1,21 +1,48 @@
+use std::sync::OnceLock;
+
+use super::schema::{ColumnType, Table};
 use tauri_plugin_sql::{Migration, MigrationKind};
 
 pub fn migration() -> Migration {
     Migration {
         version: 20260119,
         description: "create transactions table",
-        sql: SQL,
+        sql: sql(),
         kind: MigrationKind::Up,
     }
 }
 
-const SQL: &str = r#"
-    CREATE TABLE IF NOT EXISTS transactions (  
-        id INTEGER PRIMARY KEY AUTOINCREMENT,  
-        debitor TEXT NOT NULL,  
-        debit REAL NOT NULL,
-        creditor TEXT NOT NULL,
-        credit REAL NOT NULL,
-        timestamp DATETIME DEFAULT CURRENT_TIMESTAMP
-    );
-"#;
+/// Reverses [`migration`]. Paired with it in `all_migration_pairs` so a rollback to any earlier
+/// version can tear this table back down.
+pub fn down() -> Migration {
+    Migration {
+        version: 20260119,
+        description: "drop transactions table",
+        sql: down_sql(),
+        kind: MigrationKind::Down,
+    }
+}
+
+fn table() -> Table {
+    Table::new("transactions")
+        .add_column("id", ColumnType::Integer.primary_key().autoincrement())
+        .add_column("debitor", ColumnType::Text.not_null())
+        .add_column("debit", ColumnType::Real.not_null())
+        .add_column("creditor", ColumnType::Text.not_null())
+        .add_column("credit", ColumnType::Real.not_null())
+        .add_column("timestamp", ColumnType::DateTime.default("CURRENT_TIMESTAMP"))
+}
+
+// `Migration::sql` needs a `&'static str`, but the builder renders a `String`. `all_migration_pairs()`
+// (behind `all_migrations()` and `down_migrations_to()`) is called more than once per process
+// (plugin registration, checksum verification, the round-trip self-test, ...), so the rendered
+// SQL is memoized rather than leaked afresh on every call.
+fn sql() -> &'static str {
+    static SQL: OnceLock<String> = OnceLock::new();
+    SQL.get_or_init(|| table().build())
+}
+
+fn down_sql() -> &'static str {
+    static SQL: OnceLock<String> = OnceLock::new();
+    SQL.get_or_init(|| table().drop_sql())
+}
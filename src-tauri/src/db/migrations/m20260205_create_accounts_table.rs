@@ -0,0 +1,45 @@
+use std::sync::OnceLock;
+
+use super::schema::{ColumnType, Table};
+use tauri_plugin_sql::{Migration, MigrationKind};
+
+pub fn migration() -> Migration {
+    Migration {
+        version: 20260205,
+        description: "create accounts table",
+        sql: sql(),
+        kind: MigrationKind::Up,
+    }
+}
+
+/// Reverses [`migration`].
+pub fn down() -> Migration {
+    Migration {
+        version: 20260205,
+        description: "drop accounts table",
+        sql: down_sql(),
+        kind: MigrationKind::Down,
+    }
+}
+
+fn table() -> Table {
+    Table::new("accounts")
+        .add_column("id", ColumnType::Integer.primary_key().autoincrement())
+        .add_column("name", ColumnType::Text.not_null())
+        .add_column("type", ColumnType::Text.not_null())
+        .add_column("currency", ColumnType::Text.default("'USD'"))
+}
+
+// `Migration::sql` needs a `&'static str`, but the builder renders a `String`. `all_migration_pairs()`
+// (behind `all_migrations()` and `down_migrations_to()`) is called more than once per process
+// (plugin registration, checksum verification, the round-trip self-test, ...), so the rendered
+// SQL is memoized rather than leaked afresh on every call.
+fn sql() -> &'static str {
+    static SQL: OnceLock<String> = OnceLock::new();
+    SQL.get_or_init(|| table().build())
+}
+
+fn down_sql() -> &'static str {
+    static SQL: OnceLock<String> = OnceLock::new();
+    SQL.get_or_init(|| table().drop_sql())
+}
@@ -0,0 +1,92 @@
+use tauri_plugin_sql::{Migration, MigrationKind};
+
+pub fn migration() -> Migration {
+    Migration {
+        version: 20260206,
+        description: "create journal entries and postings",
+        sql: SQL,
+        kind: MigrationKind::Up,
+    }
+}
+
+/// Reverses [`migration`].
+pub fn down() -> Migration {
+    Migration {
+        version: 20260206,
+        description: "drop journal entries and postings",
+        sql: DOWN_SQL,
+        kind: MigrationKind::Down,
+    }
+}
+
+// Postings are free to be inserted while their journal entry is still `draft`, since a SQLite
+// trigger fires per row and can't see the rest of a multi-row INSERT. The zero-sum balance is
+// enforced by `enforce_balanced_postings` when an entry transitions to `posted`, and by
+// `enforce_balanced_postings_on_insert` when a row is inserted already `posted` (skipping the
+// transition entirely) — by either path every posting it owns is expected to already exist. Once
+// posted, the entry's postings are frozen: the `guard_posted_*` triggers reject any further
+// insert/update/delete against them (including stealing a posting away to a different, draft
+// entry), so the checked balance can't be silently undone afterwards.
+const SQL: &str = r#"
+    CREATE TABLE IF NOT EXISTS journal_entries (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        description TEXT NOT NULL,
+        status TEXT NOT NULL DEFAULT 'draft',
+        timestamp DATETIME DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE TABLE IF NOT EXISTS postings (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        journal_entry_id INTEGER NOT NULL REFERENCES journal_entries(id),
+        account_id INTEGER NOT NULL REFERENCES accounts(id),
+        amount REAL NOT NULL
+    );
+
+    CREATE TRIGGER IF NOT EXISTS enforce_balanced_postings
+    AFTER UPDATE OF status ON journal_entries
+    WHEN NEW.status = 'posted' AND OLD.status != 'posted'
+    BEGIN
+        SELECT RAISE(ABORT, 'journal entry postings do not sum to zero')
+        WHERE (SELECT IFNULL(SUM(amount), 0) FROM postings WHERE journal_entry_id = NEW.id) != 0;
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS enforce_balanced_postings_on_insert
+    AFTER INSERT ON journal_entries
+    WHEN NEW.status = 'posted'
+    BEGIN
+        SELECT RAISE(ABORT, 'journal entry postings do not sum to zero')
+        WHERE (SELECT IFNULL(SUM(amount), 0) FROM postings WHERE journal_entry_id = NEW.id) != 0;
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS guard_posted_postings_insert
+    AFTER INSERT ON postings
+    WHEN (SELECT status FROM journal_entries WHERE id = NEW.journal_entry_id) = 'posted'
+    BEGIN
+        SELECT RAISE(ABORT, 'cannot add a posting to a posted journal entry');
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS guard_posted_postings_update
+    AFTER UPDATE ON postings
+    WHEN (SELECT status FROM journal_entries WHERE id = NEW.journal_entry_id) = 'posted'
+      OR (SELECT status FROM journal_entries WHERE id = OLD.journal_entry_id) = 'posted'
+    BEGIN
+        SELECT RAISE(ABORT, 'cannot modify a posting of a posted journal entry');
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS guard_posted_postings_delete
+    AFTER DELETE ON postings
+    WHEN (SELECT status FROM journal_entries WHERE id = OLD.journal_entry_id) = 'posted'
+    BEGIN
+        SELECT RAISE(ABORT, 'cannot delete a posting of a posted journal entry');
+    END;
+"#;
+
+const DOWN_SQL: &str = r#"
+    DROP TRIGGER IF EXISTS guard_posted_postings_delete;
+    DROP TRIGGER IF EXISTS guard_posted_postings_update;
+    DROP TRIGGER IF EXISTS guard_posted_postings_insert;
+    DROP TRIGGER IF EXISTS enforce_balanced_postings_on_insert;
+    DROP TRIGGER IF EXISTS enforce_balanced_postings;
+    DROP TABLE IF EXISTS postings;
+    DROP TABLE IF EXISTS journal_entries;
+"#;
@@ -0,0 +1,91 @@
+use tauri_plugin_sql::{Migration, MigrationKind};
+
+pub fn migration() -> Migration {
+    Migration {
+        version: 20260207,
+        description: "backfill transactions into journal entries and postings",
+        sql: SQL,
+        kind: MigrationKind::Up,
+    }
+}
+
+/// Reverses [`migration`].
+pub fn down() -> Migration {
+    Migration {
+        version: 20260207,
+        description: "remove backfilled journal entries and postings",
+        sql: DOWN_SQL,
+        kind: MigrationKind::Down,
+    }
+}
+
+// One account per distinct party name seen in `transactions`, then one journal entry with two
+// postings per row: the debitor's balance moves down, the creditor's moves up. Entries are
+// immediately marked `posted`, which is what actually runs the zero-sum check from
+// `enforce_balanced_postings`.
+//
+// The old `transactions` schema never guaranteed `debit == credit` — that's the defect this
+// migration exists to fix — so a legacy row where they differ would otherwise abort the whole
+// migration the moment its entry is posted. Rather than fail the migration, any such row is
+// reconciled with a third posting to a `Suspense` account for the difference, so every entry
+// balances before it's posted. This keeps the backfill unconditional while still making the
+// discrepancy visible: every imbalance in the old data lands in `Suspense` instead of vanishing.
+const SQL: &str = r#"
+    INSERT INTO accounts (name, type, currency)
+    SELECT DISTINCT debitor, 'unknown', 'USD' FROM transactions
+    WHERE debitor NOT IN (SELECT name FROM accounts);
+
+    INSERT INTO accounts (name, type, currency)
+    SELECT DISTINCT creditor, 'unknown', 'USD' FROM transactions
+    WHERE creditor NOT IN (SELECT name FROM accounts);
+
+    INSERT INTO accounts (name, type, currency)
+    SELECT 'Suspense', 'suspense', 'USD'
+    WHERE EXISTS (SELECT 1 FROM transactions WHERE debit != credit)
+      AND 'Suspense' NOT IN (SELECT name FROM accounts);
+
+    INSERT INTO journal_entries (description, status, timestamp)
+    SELECT 'backfilled from transactions #' || id, 'draft', timestamp FROM transactions;
+
+    INSERT INTO postings (journal_entry_id, account_id, amount)
+    SELECT je.id, a.id, -t.debit
+    FROM transactions t
+    JOIN journal_entries je ON je.description = 'backfilled from transactions #' || t.id
+    JOIN accounts a ON a.name = t.debitor;
+
+    INSERT INTO postings (journal_entry_id, account_id, amount)
+    SELECT je.id, a.id, t.credit
+    FROM transactions t
+    JOIN journal_entries je ON je.description = 'backfilled from transactions #' || t.id
+    JOIN accounts a ON a.name = t.creditor;
+
+    INSERT INTO postings (journal_entry_id, account_id, amount)
+    SELECT je.id, a.id, t.debit - t.credit
+    FROM transactions t
+    JOIN journal_entries je ON je.description = 'backfilled from transactions #' || t.id
+    JOIN accounts a ON a.name = 'Suspense'
+    WHERE t.debit != t.credit;
+
+    UPDATE journal_entries SET status = 'posted'
+    WHERE description LIKE 'backfilled from transactions #%';
+"#;
+
+// Reversing `UPDATE journal_entries SET status = 'posted'` back to `draft` first is required
+// so the `guard_posted_postings_delete` trigger (added in m20260206) doesn't reject the delete
+// below. Accounts are tagged `type = 'unknown'` or `type = 'suspense'` by `migration()`, so only those
+// left unreferenced by any remaining posting are removed here — an account this migration
+// created that's since picked up real postings elsewhere is left alone rather than deleted out
+// from under them.
+const DOWN_SQL: &str = r#"
+    UPDATE journal_entries SET status = 'draft'
+    WHERE description LIKE 'backfilled from transactions #%';
+
+    DELETE FROM postings WHERE journal_entry_id IN (
+        SELECT id FROM journal_entries WHERE description LIKE 'backfilled from transactions #%'
+    );
+    DELETE FROM journal_entries WHERE description LIKE 'backfilled from transactions #%';
+
+    DELETE FROM accounts
+    WHERE type IN ('unknown', 'suspense')
+      AND id NOT IN (SELECT account_id FROM postings);
+"#;
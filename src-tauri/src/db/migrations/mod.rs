@@ -1,12 +1,44 @@
 mod m20260119_create_transactions_table;
+mod m20260205_create_accounts_table;
+mod m20260206_create_journal_entries_and_postings;
+mod m20260207_backfill_transactions_into_postings;
+pub mod checksum;
+pub mod schema;
+#[cfg(test)]
+mod self_test;
 
+use rusqlite::Connection;
 use tauri_plugin_sql::Migration;
 
-pub fn all_migrations() -> Vec<Migration> {
-    let mut migrations = vec![
+pub use checksum::{ChecksumError, ChecksumMismatch};
+
+/// Every migration module, paired as (up, down), in the order they were authored. This is the
+/// single source of truth that both `all_migrations` and `down_migrations_to` draw from, so the
+/// two directions can never drift out of sync with each other.
+fn all_migration_pairs() -> Vec<(Migration, Migration)> {
+    vec![
         // Add future migrations here
-        m20260119_create_transactions_table::migration(),
-    ];
+        (
+            m20260119_create_transactions_table::migration(),
+            m20260119_create_transactions_table::down(),
+        ),
+        (
+            m20260205_create_accounts_table::migration(),
+            m20260205_create_accounts_table::down(),
+        ),
+        (
+            m20260206_create_journal_entries_and_postings::migration(),
+            m20260206_create_journal_entries_and_postings::down(),
+        ),
+        (
+            m20260207_backfill_transactions_into_postings::migration(),
+            m20260207_backfill_transactions_into_postings::down(),
+        ),
+    ]
+}
+
+pub fn all_migrations() -> Vec<Migration> {
+    let mut migrations: Vec<Migration> = all_migration_pairs().into_iter().map(|(up, _)| up).collect();
 
     migrations.sort_by_key(|m| m.version);
 
@@ -26,3 +58,25 @@ pub fn all_migrations() -> Vec<Migration> {
 
     migrations
 }
+
+/// Returns the down migrations needed to roll back from the latest applied version to
+/// `target_version`, ordered newest-first so each one is undone in the reverse order it was
+/// applied in. Migrations at or below `target_version` are left alone.
+pub fn down_migrations_to(target_version: i64) -> Vec<Migration> {
+    let mut migrations: Vec<Migration> = all_migration_pairs()
+        .into_iter()
+        .filter(|(up, _)| up.version > target_version)
+        .map(|(_, down)| down)
+        .collect();
+
+    migrations.sort_by_key(|m| std::cmp::Reverse(m.version));
+
+    migrations
+}
+
+/// Verifies that no already-applied migration has been edited since it ran, recording checksums
+/// for any migration seen for the first time. Call this before `all_migrations()` is handed to
+/// the SQL plugin, so a changed migration fails loudly instead of silently diverging the schema.
+pub fn verify_checksums(conn: &Connection) -> Result<(), ChecksumError> {
+    checksum::verify_checksums(conn, &all_migrations())
+}
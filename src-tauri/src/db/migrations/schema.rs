@@ -0,0 +1,145 @@
+//! A small, type-safe builder for the `CREATE TABLE` statements migrations embed.
+//!
+//! Instead of hand-writing SQL strings, a migration describes its columns with [`ColumnType`]
+//! and [`Table`], then calls [`Table::build`] to render the statement once, in one place, so
+//! every migration follows the same formatting and the column types aren't just documentation.
+
+/// A column's underlying SQLite type. Modifiers (`NOT NULL`, `DEFAULT`, ...) are attached via the
+/// methods on this type, which return a [`Column`] ready to hand to [`Table::add_column`].
+pub enum ColumnType {
+    Integer,
+    Text,
+    Varchar(u16),
+    Real,
+    DateTime,
+}
+
+impl ColumnType {
+    fn sql(&self) -> String {
+        match self {
+            ColumnType::Integer => "INTEGER".to_string(),
+            ColumnType::Text => "TEXT".to_string(),
+            ColumnType::Varchar(len) => format!("VARCHAR({len})"),
+            ColumnType::Real => "REAL".to_string(),
+            ColumnType::DateTime => "DATETIME".to_string(),
+        }
+    }
+
+    pub fn not_null(self) -> Column {
+        Column::new(self).not_null()
+    }
+
+    pub fn primary_key(self) -> Column {
+        Column::new(self).primary_key()
+    }
+
+    pub fn default(self, expr: &'static str) -> Column {
+        Column::new(self).default(expr)
+    }
+}
+
+/// A column and its modifiers, built up from a [`ColumnType`].
+pub struct Column {
+    ty: ColumnType,
+    not_null: bool,
+    primary_key: bool,
+    autoincrement: bool,
+    default: Option<&'static str>,
+}
+
+impl Column {
+    fn new(ty: ColumnType) -> Self {
+        Self {
+            ty,
+            not_null: false,
+            primary_key: false,
+            autoincrement: false,
+            default: None,
+        }
+    }
+
+    pub fn not_null(mut self) -> Self {
+        self.not_null = true;
+        self
+    }
+
+    pub fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+        self
+    }
+
+    pub fn autoincrement(mut self) -> Self {
+        self.autoincrement = true;
+        self
+    }
+
+    pub fn default(mut self, expr: &'static str) -> Self {
+        self.default = Some(expr);
+        self
+    }
+
+    fn render(&self, name: &str) -> String {
+        let mut parts = vec![name.to_string(), self.ty.sql()];
+
+        if self.primary_key {
+            parts.push("PRIMARY KEY".to_string());
+        }
+        if self.autoincrement {
+            parts.push("AUTOINCREMENT".to_string());
+        }
+        if self.not_null {
+            parts.push("NOT NULL".to_string());
+        }
+        if let Some(default) = self.default {
+            parts.push(format!("DEFAULT {default}"));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl From<ColumnType> for Column {
+    fn from(ty: ColumnType) -> Self {
+        Column::new(ty)
+    }
+}
+
+/// Builds a `CREATE TABLE IF NOT EXISTS` statement from a name and an ordered list of columns.
+pub struct Table {
+    name: &'static str,
+    columns: Vec<(&'static str, Column)>,
+}
+
+impl Table {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            columns: Vec::new(),
+        }
+    }
+
+    pub fn add_column(mut self, name: &'static str, column: impl Into<Column>) -> Self {
+        self.columns.push((name, column.into()));
+        self
+    }
+
+    /// Renders the `CREATE TABLE IF NOT EXISTS` statement for this table.
+    pub fn build(&self) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .map(|(name, column)| column.render(name))
+            .collect::<Vec<_>>()
+            .join(",\n        ");
+
+        format!(
+            "CREATE TABLE IF NOT EXISTS {} (\n        {}\n    );",
+            self.name, columns
+        )
+    }
+
+    /// Renders the `DROP TABLE IF EXISTS` statement that undoes [`Table::build`].
+    pub fn drop_sql(&self) -> String {
+        format!("DROP TABLE IF EXISTS {};", self.name)
+    }
+}
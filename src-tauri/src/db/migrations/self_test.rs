@@ -0,0 +1,50 @@
+//! Round-trips every migration against an in-memory SQLite database to catch broken SQL,
+//! ordering gaps, and non-reversible DDL before it ships.
+
+use rusqlite::Connection;
+
+use super::all_migration_pairs;
+
+/// Applies every up migration in version order, then every down migration in reverse order,
+/// against a fresh in-memory database, asserting each step runs cleanly and that no user table is
+/// left behind once every migration has been rolled back.
+///
+/// This is the same validation the `#[cfg(debug_assertions)]` duplicate-version check in
+/// `all_migrations` is meant to complement: that one catches version collisions, this one catches
+/// migrations that don't actually apply or reverse cleanly.
+pub fn assert_migrations_round_trip() {
+    let conn = Connection::open_in_memory().expect("failed to open in-memory sqlite database");
+
+    let mut pairs = all_migration_pairs();
+    pairs.sort_by_key(|(up, _)| up.version);
+
+    for (up, _) in &pairs {
+        conn.execute_batch(up.sql).unwrap_or_else(|err| {
+            panic!("up migration {} ({}) failed: {err}", up.version, up.description)
+        });
+    }
+
+    for (_, down) in pairs.iter().rev() {
+        conn.execute_batch(down.sql).unwrap_or_else(|err| {
+            panic!("down migration {} ({}) failed: {err}", down.version, down.description)
+        });
+    }
+
+    let remaining_tables: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+            [],
+            |row| row.get(0),
+        )
+        .expect("failed to inspect sqlite_master after rollback");
+
+    assert_eq!(
+        remaining_tables, 0,
+        "schema was not empty after rolling back every migration"
+    );
+}
+
+#[test]
+fn migrations_round_trip() {
+    assert_migrations_round_trip();
+}